@@ -6,17 +6,101 @@
 use crate::cli;
 use crate::ui::{self, Action};
 use core::cmp;
+use crossterm::event::{Event as TermEvent, KeyCode};
+use crossterm::style::Color;
+use crossterm::{cursor, queue, style, terminal};
 use fs_extra::file::copy;
 use fs_extra::file::move_file;
 use fs_extra::file::remove;
-use sdl2::image::LoadTexture;
 use sdl2::rect::Rect;
 use sdl2::render::{TextureCreator, WindowCanvas};
 use sdl2::video::WindowContext;
 use sdl2::Sdl;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::io::ErrorKind;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Maximum number of decoded-but-not-yet-uploaded images the prefetch cache keeps around.
+const PREFETCH_CACHE_CAP: usize = 16;
+
+/// How many images ahead of and behind the current index the worker should prefetch.
+const PREFETCH_LOOKAHEAD: usize = 2;
+
+/// How long to sleep between event-loop ticks when there is no animation frame to wait out.
+const PLAYBACK_IDLE_TICK: Duration = Duration::from_millis(16);
+
+/// Factor `Action::ZoomIn`/`Action::ZoomOut` multiply or divide the zoom level by.
+const ZOOM_STEP: f32 = 1.1;
+
+/// Zoom level of the un-zoomed, fit-to-window view. Also the floor for `zoom_out`.
+const MIN_ZOOM: f32 = 1.0;
+
+/// Ceiling for `zoom_in`, past which detail inspection stops being useful.
+const MAX_ZOOM: f32 = 8.0;
+
+/// Raw decoded pixels for one image, since `sdl2::render::Texture` is not `Send`.
+struct DecodedImage {
+    width: u32,
+    height: u32,
+    /// Tightly packed RGBA8 pixels, row-major.
+    rgba: Vec<u8>,
+}
+
+/// A hint sent to the prefetch worker asking it to decode the image at `index`.
+struct PrefetchRequest {
+    index: usize,
+    path: PathBuf,
+    /// `Program::generation` at request time, echoed back in the response.
+    generation: u64,
+}
+
+/// The result of decoding a prefetch request, sent back to the main thread.
+struct PrefetchResponse {
+    index: usize,
+    image: Option<DecodedImage>,
+    generation: u64,
+}
+
+/// Spawns the background thread that decodes images ahead of and behind the viewer.
+fn spawn_prefetch_worker() -> (
+    mpsc::Sender<PrefetchRequest>,
+    mpsc::Receiver<PrefetchResponse>,
+) {
+    let (request_tx, request_rx) = mpsc::channel::<PrefetchRequest>();
+    let (response_tx, response_rx) = mpsc::channel::<PrefetchResponse>();
+
+    thread::spawn(move || {
+        for request in request_rx {
+            let image = match decode_oriented(&request.path) {
+                Ok(decoded) => Some(decoded),
+                Err(e) => {
+                    eprintln!("failed to prefetch image {:?}: {}", request.path, e);
+                    None
+                }
+            };
+            if response_tx
+                .send(PrefetchResponse {
+                    index: request.index,
+                    image,
+                    generation: request.generation,
+                })
+                .is_err()
+            {
+                // Main thread is gone, nothing left to do.
+                break;
+            }
+        }
+    });
+
+    (request_tx, response_rx)
+}
 
 /// Compute increment of skips
 /// Does not account for overflow or underflow of vector
@@ -28,6 +112,61 @@ fn compute_skip_size(images: &[PathBuf]) -> usize {
     cmp::max(1usize, skip_size)
 }
 
+/// Returns the indices a bulk operation should act on: `selected` if non-empty, else just `index`.
+fn selected_or_current(selected: &BTreeSet<usize>, index: usize) -> Vec<usize> {
+    if selected.is_empty() {
+        vec![index]
+    } else {
+        selected.iter().copied().collect()
+    }
+}
+
+/// Converts a decoded animation's frames into `self.frames`' storage shape, rounding each
+/// frame's delay to the nearest millisecond.
+fn collect_animation_frames(frames: Vec<image::Frame>) -> Vec<(DecodedImage, Duration)> {
+    frames
+        .into_iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay_ms = (numer as f64 / denom.max(1) as f64).round() as u64;
+            let delay = Duration::from_millis(delay_ms);
+            let buffer = frame.into_buffer();
+            let (width, height) = buffer.dimensions();
+            (
+                DecodedImage {
+                    width,
+                    height,
+                    rgba: buffer.into_raw(),
+                },
+                delay,
+            )
+        })
+        .collect()
+}
+
+/// Decodes `path` as an animated GIF. Returns `None` if it is not a GIF at all; a single-frame
+/// GIF still comes back as a one-element `Vec` so the caller can treat it as static.
+fn decode_gif_frames(path: &Path) -> Option<Vec<(DecodedImage, Duration)>> {
+    let file = std::fs::File::open(path).ok()?;
+    let decoder = image::codecs::gif::GifDecoder::new(std::io::BufReader::new(file)).ok()?;
+    let frames = image::AnimationDecoder::into_frames(decoder)
+        .collect_frames()
+        .ok()?;
+    Some(collect_animation_frames(frames))
+}
+
+/// Decodes `path` as an animated PNG (APNG). Returns `None` if it is not a PNG, or is a PNG
+/// without the `acTL`/`fcTL` chunks that mark it as animated.
+fn decode_apng_frames(path: &Path) -> Option<Vec<(DecodedImage, Duration)>> {
+    let file = std::fs::File::open(path).ok()?;
+    let decoder = image::codecs::png::PngDecoder::new(std::io::BufReader::new(file)).ok()?;
+    let decoder = decoder.apng().ok()?;
+    let frames = image::AnimationDecoder::into_frames(decoder)
+        .collect_frames()
+        .ok()?;
+    Some(collect_animation_frames(frames))
+}
+
 /// Program contains all information needed to run the event loop and render the images to screen
 pub struct Program {
     sdl_context: Sdl,
@@ -36,7 +175,34 @@ pub struct Program {
     images: Vec<PathBuf>,
     dest_folder: PathBuf,
     index: usize,
+    /// Indices into `images` that are currently marked, in addition to `index`.
+    /// Bulk operations (copy/move/delete) act on this set when it is non-empty.
+    selected: BTreeSet<usize>,
     ui_state: ui::State,
+    /// Sends decode hints to the prefetch worker.
+    prefetch_tx: mpsc::Sender<PrefetchRequest>,
+    /// Receives decoded images from the prefetch worker.
+    prefetch_rx: mpsc::Receiver<PrefetchResponse>,
+    /// Decoded images the worker produced, keyed by index, waiting to be uploaded.
+    prefetch_cache: HashMap<usize, DecodedImage>,
+    /// Indices already sent to the worker whose response hasn't arrived yet.
+    prefetch_pending: HashSet<usize>,
+    /// Bumped on every `images` mutation; stamped on requests so stale responses can be dropped.
+    generation: u64,
+    /// Decoded frames of the currently displayed image, each paired with how long it should
+    /// stay on screen. Empty whenever the current image is not a multi-frame animation, in
+    /// which case `render` takes the ordinary static-image fast path.
+    frames: Vec<(DecodedImage, Duration)>,
+    /// Index into `frames` of the frame currently on screen.
+    current_frame: usize,
+    /// When the current frame started being displayed.
+    frame_started_at: Instant,
+    /// Whether animation playback is advancing. Toggled by `Action::TogglePlayPause`.
+    playing: bool,
+    /// Current zoom level. `1.0` means the ordinary fit-to-window view.
+    zoom: f32,
+    /// Pan offset, in destination pixels, applied on top of the zoomed fit rect.
+    pan: (i32, i32),
 }
 
 impl Program {
@@ -45,6 +211,12 @@ impl Program {
     /// creator.
     pub fn init() -> Result<Program, String> {
         let args = cli::cli()?;
+        // `--term` has no use for a window, canvas, or texture creator, so it never constructs
+        // a `Program` at all; it runs its own headless loop to completion instead.
+        if args.term {
+            run_term_mode(args.files)?;
+            std::process::exit(0);
+        }
         let images = args.files;
         let dest_folder = args.dest_folder;
         let sdl_context = sdl2::init()?;
@@ -70,42 +242,209 @@ impl Program {
             left_shift: false,
             right_shift: false,
         };
-        Ok(Program {
+        let (prefetch_tx, prefetch_rx) = spawn_prefetch_worker();
+        let mut program = Program {
             sdl_context,
             canvas,
             texture_creator,
             images,
             dest_folder,
             index: 0,
+            selected: BTreeSet::new(),
             ui_state,
-        })
+            prefetch_tx,
+            prefetch_rx,
+            prefetch_cache: HashMap::new(),
+            prefetch_pending: HashSet::new(),
+            generation: 0,
+            frames: Vec::new(),
+            current_frame: 0,
+            frame_started_at: Instant::now(),
+            playing: true,
+            zoom: MIN_ZOOM,
+            pan: (0, 0),
+        };
+        program.request_neighbor_prefetch();
+        program.on_index_changed();
+        Ok(program)
     }
 
     /// render loads the image at the path in the images path vector located at the index and
-    /// renders to screen
+    /// renders to screen, preferring an already-decoded image from the prefetch cache over
+    /// decoding on the hot path.
     pub fn render(&mut self) -> Result<(), String> {
         if self.images.is_empty() {
             return self.render_blank();
         }
-        let texture = match self.texture_creator.load_texture(&self.images[self.index]) {
-            Ok(t) => t,
-            Err(e) => {
-                eprintln!("failed to render image {}", e);
-                return Ok(());
+        self.drain_prefetch_responses();
+        self.request_neighbor_prefetch();
+
+        // An animated image already has its frames decoded; everything else goes through the
+        // prefetch cache or, failing that, the synchronous static-image fast path. Either way
+        // the pixels are already EXIF-oriented, so `query`'s width/height below are already the
+        // post-rotation dimensions `make_dst` needs.
+        //
+        // The buffer is uploaded by mutable reference rather than cloned: `upload_decoded` only
+        // needs it borrowed for the duration of the upload, so a cached or animated frame's
+        // pixels never get copied just to satisfy `Surface::from_data`.
+        let texture = if !self.frames.is_empty() {
+            let current_frame = self.current_frame;
+            match upload_decoded(&self.texture_creator, &mut self.frames[current_frame].0) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("failed to upload image {}", e);
+                    return Ok(());
+                }
+            }
+        } else {
+            let mut decoded = match self.prefetch_cache.remove(&self.index) {
+                Some(decoded) => decoded,
+                None => match decode_oriented(&self.images[self.index]) {
+                    Ok(decoded) => decoded,
+                    Err(e) => {
+                        eprintln!("failed to render image {}", e);
+                        return Ok(());
+                    }
+                },
+            };
+            match upload_decoded(&self.texture_creator, &mut decoded) {
+                Ok(t) => t,
+                Err(e) => {
+                    eprintln!("failed to upload image {}", e);
+                    return Ok(());
+                }
             }
         };
         let query = texture.query();
         let target = self.canvas.viewport();
-        let dest = make_dst(query.width, query.height, target.width(), target.height());
         self.canvas.clear();
-        if let Err(e) = self.canvas.copy(&texture, None, dest) {
-            eprintln!("Failed to copy image to screen {}", e);
-            return Ok(());
+        // The common case is the plain fit-to-window view; only compute a cropped/scaled rect
+        // pair once the user has actually zoomed or panned.
+        if self.zoom == MIN_ZOOM && self.pan == (0, 0) {
+            let dest = make_dst(query.width, query.height, target.width(), target.height());
+            if let Err(e) = self.canvas.copy(&texture, None, dest) {
+                eprintln!("Failed to copy image to screen {}", e);
+                return Ok(());
+            }
+        } else {
+            if let Some((src, dest)) = make_zoomed_rects(
+                query.width,
+                query.height,
+                target.width(),
+                target.height(),
+                self.zoom,
+                self.pan,
+            ) {
+                if let Err(e) = self.canvas.copy(&texture, src, dest) {
+                    eprintln!("Failed to copy image to screen {}", e);
+                    return Ok(());
+                }
+            }
         }
         self.canvas.present();
         Ok(())
     }
 
+    /// Drains any images the prefetch worker has finished decoding into the bounded cache,
+    /// evicting the entry farthest from `self.index` when the cache is over capacity.
+    fn drain_prefetch_responses(&mut self) {
+        while let Ok(response) = self.prefetch_rx.try_recv() {
+            self.prefetch_pending.remove(&response.index);
+            if response.generation != self.generation {
+                continue; // images shifted since this was requested; the index no longer lines up
+            }
+            if let Some(decoded) = response.image {
+                self.prefetch_cache.insert(response.index, decoded);
+            }
+        }
+        while self.prefetch_cache.len() > PREFETCH_CACHE_CAP {
+            let current = self.index;
+            let farthest = self
+                .prefetch_cache
+                .keys()
+                .copied()
+                .max_by_key(|&i| (i as isize - current as isize).abs())
+                .expect("cache is over capacity so it must be non-empty");
+            self.prefetch_cache.remove(&farthest);
+        }
+    }
+
+    /// Sends decode hints for the images just ahead of and behind `self.index` that are not
+    /// already cached.
+    fn request_neighbor_prefetch(&mut self) {
+        if self.images.is_empty() {
+            return;
+        }
+        for offset in 1..=PREFETCH_LOOKAHEAD {
+            self.request_prefetch(self.index.checked_add(offset));
+            self.request_prefetch(self.index.checked_sub(offset));
+        }
+    }
+
+    /// Resets all per-image view state after `self.index` changes: reloads animation frames and
+    /// returns zoom/pan to the default fit-to-window view.
+    fn on_index_changed(&mut self) {
+        self.load_current_frames();
+        self.zoom = MIN_ZOOM;
+        self.pan = (0, 0);
+    }
+
+    /// Decodes the current image's frames if it is a multi-frame GIF or APNG, resetting playback
+    /// state. Leaves `self.frames` empty for a static image.
+    fn load_current_frames(&mut self) {
+        self.frames.clear();
+        self.current_frame = 0;
+        self.frame_started_at = Instant::now();
+        if self.images.is_empty() {
+            return;
+        }
+        let path = &self.images[self.index];
+
+        let frames = decode_gif_frames(path).or_else(|| decode_apng_frames(path));
+        let Some(frames) = frames else {
+            return; // not an animated GIF or APNG; treat as a static image
+        };
+        if frames.len() <= 1 {
+            return; // single-frame animations behave like a static image
+        }
+        self.frames = frames;
+    }
+
+    /// If the current image is animated and playback is not paused, advances to the next frame
+    /// once its delay has elapsed and re-renders. Returns how long the caller may sleep.
+    fn tick_animation(&mut self) -> Result<Duration, String> {
+        if !self.playing || self.frames.len() <= 1 {
+            return Ok(PLAYBACK_IDLE_TICK);
+        }
+        let delay = self.frames[self.current_frame].1;
+        let elapsed = self.frame_started_at.elapsed();
+        if elapsed < delay {
+            return Ok(cmp::min(delay - elapsed, PLAYBACK_IDLE_TICK));
+        }
+        self.current_frame = (self.current_frame + 1) % self.frames.len();
+        self.frame_started_at = Instant::now();
+        self.render()?;
+        Ok(PLAYBACK_IDLE_TICK)
+    }
+
+    /// Sends a single decode hint to the worker if `index` is in bounds and not already cached
+    /// or awaiting a response.
+    fn request_prefetch(&mut self, index: Option<usize>) {
+        let index = match index {
+            Some(i) if i < self.images.len() => i,
+            _ => return,
+        };
+        if self.prefetch_cache.contains_key(&index) || self.prefetch_pending.contains(&index) {
+            return;
+        }
+        self.prefetch_pending.insert(index);
+        let _ = self.prefetch_tx.send(PrefetchRequest {
+            index,
+            path: self.images[index].clone(),
+            generation: self.generation,
+        });
+    }
+
     fn render_blank(&mut self) -> Result<(), String> {
         self.canvas.clear();
         self.canvas.present();
@@ -123,6 +462,7 @@ impl Program {
         else {
             self.index = self.images.len() - 1;
         }
+        self.on_index_changed();
         self.render()
     }
 
@@ -142,6 +482,57 @@ impl Program {
         }
     }
 
+    /// Toggles whether the image currently at `self.index` is marked.
+    fn toggle_mark(&mut self) {
+        if !self.selected.remove(&self.index) {
+            self.selected.insert(self.index);
+        }
+    }
+
+    /// Marks every unmarked image and unmarks every marked one.
+    fn invert_selection(&mut self) {
+        self.selected = (0..self.images.len())
+            .filter(|i| !self.selected.contains(i))
+            .collect();
+    }
+
+    /// Clears the current selection.
+    fn clear_selection(&mut self) {
+        self.selected.clear();
+    }
+
+    /// Returns the indices that a bulk operation (copy/move/delete) should act on: the current
+    /// selection if non-empty, otherwise just the currently viewed image.
+    fn selection_or_current(&self) -> Vec<usize> {
+        selected_or_current(&self.selected, self.index)
+    }
+
+    /// Removes a batch of images by index. `indices` must be sorted in descending order so that
+    /// removing an earlier (larger) index never shifts the position of a later (smaller) one
+    /// still to be processed.
+    fn remove_images(&mut self, indices: &[usize]) {
+        let original_index = self.index;
+        for &index in indices {
+            self.remove_image(index);
+        }
+        // `remove_image`'s adjustment assumes a single removal, so redo it from scratch: shift
+        // the original index down by however many removed indices were below it, then clamp.
+        let removed_below = indices.iter().filter(|&&i| i < original_index).count();
+        self.index = original_index.saturating_sub(removed_below);
+        if self.images.is_empty() {
+            self.index = 0;
+        } else if self.index >= self.images.len() {
+            self.index = self.images.len() - 1;
+        }
+        self.selected.clear();
+        // Shifted indices make the cache and any in-flight request stale; bump the generation so
+        // `drain_prefetch_responses` can drop responses keyed to the old layout.
+        self.prefetch_cache.clear();
+        self.prefetch_pending.clear();
+        self.generation += 1;
+        self.on_index_changed();
+    }
+
     fn decrement(&mut self, step: usize) -> Result<(), String> {
         if self.index >= step {
             self.index -= step;
@@ -150,6 +541,7 @@ impl Program {
         else {
             self.index = 0;
         }
+        self.on_index_changed();
         self.render()
     }
 
@@ -167,6 +559,7 @@ impl Program {
 
     fn first(&mut self) -> Result<(), String> {
         self.index = 0;
+        self.on_index_changed();
         self.render()
     }
 
@@ -176,6 +569,29 @@ impl Program {
         } else {
             self.index = self.images.len() - 1;
         }
+        self.on_index_changed();
+        self.render()
+    }
+
+    /// Zooms in on the current image around its center, clamped to `MAX_ZOOM`.
+    fn zoom_in(&mut self) -> Result<(), String> {
+        self.zoom = (self.zoom * ZOOM_STEP).min(MAX_ZOOM);
+        self.render()
+    }
+
+    /// Zooms out of the current image, clamped to `MIN_ZOOM`, resetting pan once back at the
+    /// default fit-to-window view.
+    fn zoom_out(&mut self) -> Result<(), String> {
+        self.zoom = (self.zoom / ZOOM_STEP).max(MIN_ZOOM);
+        if self.zoom == MIN_ZOOM {
+            self.pan = (0, 0);
+        }
+        self.render()
+    }
+
+    /// Pans the current image by `(dx, dy)` destination pixels, e.g. from arrow keys or a drag.
+    fn pan(&mut self, dx: i32, dy: i32) -> Result<(), String> {
+        self.pan = (self.pan.0 + dx, self.pan.1 + dy);
         self.render()
     }
 
@@ -196,7 +612,8 @@ impl Program {
         Ok(newname)
     }
 
-    /// Copies currently rendered image to dest directory
+    /// Copies currently rendered image, or the whole selection if one is marked, to dest
+    /// directory
     /// TODO: Handle when file already exists in dest directory
     fn copy_image(&mut self) -> Result<(), String> {
         // Check if there are any images
@@ -204,85 +621,89 @@ impl Program {
             return Err("No image to copy".to_string());
         }
         let opt = &fs_extra::file::CopyOptions::new();
-        let filepath = self.images.get(self.index).unwrap_or_else(|| {
-            panic!(format!(
-                "image index {} > max image index {}",
-                self.index,
-                self.images.len()
-            ))
-        });
-        let newname = self.construct_dest_filepath(filepath)?;
-        copy(filepath, newname, opt).map_err(|e| e.to_string())?;
+        for index in self.selection_or_current() {
+            let filepath = &self.images[index];
+            let newname = self.construct_dest_filepath(filepath)?;
+            copy(filepath, newname, opt).map_err(|e| e.to_string())?;
+        }
         Ok(())
     }
 
-    /// Moves image currently being viewed to destination folder
+    /// Moves the image currently being viewed, or the whole selection if one is marked, to
+    /// destination folder
     fn move_image(&mut self) -> Result<(), String> {
         // Check if there is an image to move
         if self.images.is_empty() {
             return Err("no images to move".to_string());
         }
-        // Retrieve current image
-        assert!(self.index < self.images.len());
-        let current_imagepath = self.images.get(self.index).unwrap_or_else(|| {
-            panic!(format!(
-                "image index {} > max image index {}",
-                self.index,
-                self.images.len()
-            ))
-        });
+        // Process indices largest-first so earlier removals don't shift the position of
+        // not-yet-processed entries.
+        let mut indices = self.selection_or_current();
+        indices.sort_unstable_by(|a, b| b.cmp(a));
 
-        let newname = self.construct_dest_filepath(&current_imagepath)?;
-        let opt = &fs_extra::file::CopyOptions::new();
+        let mut moved = Vec::with_capacity(indices.len());
+        for index in &indices {
+            let current_imagepath = &self.images[*index];
+            let newname = match self.construct_dest_filepath(current_imagepath) {
+                Ok(newname) => newname,
+                Err(e) => {
+                    self.remove_images(&moved);
+                    return Err(e);
+                }
+            };
+            let opt = &fs_extra::file::CopyOptions::new();
 
-        // Attempt to move image
-        if let Err(e) = move_file(current_imagepath, newname, opt) {
-            return Err(format!(
-                "Failed to remove image `{:?}`: {}",
-                current_imagepath,
-                e.to_string()
-            ));
+            // Attempt to move image
+            if let Err(e) = move_file(current_imagepath, newname, opt) {
+                let msg = format!("Failed to remove image `{:?}`: {}", current_imagepath, e);
+                // Untrack everything moved so far before bailing, so `self.images` never keeps
+                // pointing at files that no longer exist at their old path.
+                self.remove_images(&moved);
+                return Err(msg);
+            }
+            moved.push(*index);
         }
 
-        // Only if successful, remove image from tracked images
-        self.remove_image(self.index);
+        // Only for images successfully moved, remove them from tracked images
+        self.remove_images(&moved);
 
-        // Moving the image automatically advanced to next image
+        // Moving the image(s) automatically advanced to next image
         // Adjust our view to reflect this
         self.render()
     }
 
-    /// Deletes image currently being viewed
+    /// Deletes the image currently being viewed, or the whole selection if one is marked
     fn delete_image(&mut self) -> Result<(), String> {
         // Check if there is an image to delete
         if self.images.is_empty() {
             return Err("no images to delete".to_string());
         }
 
-        // Retrieve current image
-        assert!(self.index < self.images.len());
-        let current_imagepath = self.images.get(self.index).unwrap_or_else(|| {
-            panic!(format!(
-                "image index {} > max image index {}",
-                self.index,
-                self.images.len()
-            ))
-        });
+        // Process indices largest-first so earlier removals don't shift the position of
+        // not-yet-processed entries.
+        let mut indices = self.selection_or_current();
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut deleted = Vec::with_capacity(indices.len());
+        for index in &indices {
+            let current_imagepath = &self.images[*index];
 
-        // Attempt to remove image
-        if let Err(e) = remove(&current_imagepath) {
-            return Err(format!(
-                "Failed to remove image `{:?}`: {}",
-                current_imagepath,
-                e.to_string()
-            ));
+            // Attempt to remove image
+            if let Err(e) = remove(current_imagepath) {
+                let msg = format!("Failed to remove image `{:?}`: {}", current_imagepath, e);
+                // Untrack everything deleted so far before bailing, so `self.images` never keeps
+                // pointing at files that no longer exist.
+                self.remove_images(&deleted);
+                return Err(msg);
+            }
+            // If we've reached past here, there was no error deleting the image
+            deleted.push(*index);
         }
-        // If we've reached past here, there was no error deleting the image
 
-        // Only if successful, remove image from tracked images
-        self.remove_image(self.index);
+        // Only for images successfully deleted, remove them from tracked images
+        self.remove_images(&deleted);
 
-        // Removing the image automatically advanced to next image
+        // Removing the image(s) automatically advanced to next image
         // Adjust our view to reflect this
         self.render()
     }
@@ -314,16 +735,88 @@ impl Program {
                     },
                     Action::First => self.first()?,
                     Action::Last => self.last()?,
+                    Action::ToggleMark => {
+                        self.toggle_mark();
+                        self.render()?
+                    }
+                    Action::InvertSelection => {
+                        self.invert_selection();
+                        self.render()?
+                    }
+                    Action::ClearSelection => {
+                        self.clear_selection();
+                        self.render()?
+                    }
+                    Action::TogglePlayPause => self.playing = !self.playing,
+                    Action::ZoomIn => self.zoom_in()?,
+                    Action::ZoomOut => self.zoom_out()?,
+                    Action::Pan(dx, dy) => self.pan(dx, dy)?,
                     Action::Noop => {}
                 }
             }
-            std::thread::sleep(Duration::from_millis(0));
+            let sleep_duration = self.tick_animation()?;
+            std::thread::sleep(sleep_duration);
         }
 
         Ok(())
     }
 }
 
+/// Uploads decoded pixels into a texture the canvas can draw, borrowing `decoded` rather than
+/// cloning it.
+fn upload_decoded(
+    texture_creator: &TextureCreator<WindowContext>,
+    decoded: &mut DecodedImage,
+) -> Result<sdl2::render::Texture, String> {
+    let pitch = decoded.width * 4;
+    let surface = sdl2::surface::Surface::from_data(
+        &mut decoded.rgba,
+        decoded.width,
+        decoded.height,
+        pitch,
+        sdl2::pixels::PixelFormatEnum::RGBA32,
+    )
+    .map_err(|e| e.to_string())?;
+    texture_creator
+        .create_texture_from_surface(&surface)
+        .map_err(|e| e.to_string())
+}
+
+/// Computes the cropped source rect and scaled/panned destination rect for a zoomed or panned
+/// image. Returns `None` if it has been panned entirely out of view.
+fn make_zoomed_rects(
+    src_x: u32,
+    src_y: u32,
+    dst_x: u32,
+    dst_y: u32,
+    zoom: f32,
+    pan: (i32, i32),
+) -> Option<(Rect, Rect)> {
+    let fit = make_dst(src_x, src_y, dst_x, dst_y);
+    let full_width = ((fit.width() as f32) * zoom).round().max(1.0) as u32;
+    let full_height = ((fit.height() as f32) * zoom).round().max(1.0) as u32;
+    let center_x = fit.x() + fit.width() as i32 / 2;
+    let center_y = fit.y() + fit.height() as i32 / 2;
+    let full_x = center_x - (full_width / 2) as i32 + pan.0;
+    let full_y = center_y - (full_height / 2) as i32 + pan.1;
+    let full_dest = Rect::new(full_x, full_y, full_width, full_height);
+
+    let viewport = Rect::new(0, 0, dst_x, dst_y);
+    let visible_dest = full_dest.intersection(viewport)?;
+
+    // Map the visible slice of the destination back into source pixel space.
+    let scale_x = src_x as f32 / full_width as f32;
+    let scale_y = src_y as f32 / full_height as f32;
+    let src_rect = Rect::new(
+        (((visible_dest.x() - full_x) as f32) * scale_x) as i32,
+        (((visible_dest.y() - full_y) as f32) * scale_y) as i32,
+        ((visible_dest.width() as f32) * scale_x) as u32,
+        ((visible_dest.height() as f32) * scale_y) as u32,
+    );
+
+    Some((src_rect, visible_dest))
+}
+
 /// make dst determines the parameters of a rectangle required to place an image correctly in
 /// the window
 fn make_dst(src_x: u32, src_y: u32, dst_x: u32, dst_y: u32) -> Rect {
@@ -356,3 +849,333 @@ fn fit_y_rect(src_x: u32, src_y: u32, dst_x: u32, dst_y: u32) -> Rect {
     let x = ((dst_x - width) as f32 / 2.0) as i32;
     Rect::new(x, 0, width, dst_y)
 }
+
+/// Ensures raw mode is disabled again once term mode exits, even on error or panic.
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> Result<Self, String> {
+        terminal::enable_raw_mode().map_err(|e| e.to_string())?;
+        Ok(RawModeGuard)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Runs a headless session that renders images directly to the terminal using Unicode
+/// half-blocks, for use over SSH or in a plain TTY with no graphical display.
+fn run_term_mode(images: Vec<PathBuf>) -> Result<(), String> {
+    if images.is_empty() {
+        return Err("no images to display".to_string());
+    }
+    let _raw_mode = RawModeGuard::enable()?;
+    let mut index = 0usize;
+    render_term_frame(&images[index])?;
+
+    loop {
+        match read_term_action()? {
+            Action::Next => {
+                if index + 1 < images.len() {
+                    index += 1;
+                    render_term_frame(&images[index])?;
+                }
+            }
+            Action::Prev => {
+                if index > 0 {
+                    index -= 1;
+                    render_term_frame(&images[index])?;
+                }
+            }
+            Action::Quit => break,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Blocks until the next keypress and maps it to the `Action` it corresponds to in term mode.
+fn read_term_action() -> Result<Action, String> {
+    loop {
+        match crossterm::event::read().map_err(|e| e.to_string())? {
+            TermEvent::Key(key) => {
+                return Ok(match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+                    KeyCode::Right | KeyCode::Char('n') | KeyCode::Char(' ') => Action::Next,
+                    KeyCode::Left | KeyCode::Char('p') => Action::Prev,
+                    _ => Action::Noop,
+                });
+            }
+            _ => continue,
+        }
+    }
+}
+
+/// Decodes `path` and prints it to the terminal as a grid of Unicode upper-half-blocks, two
+/// vertical pixels per cell.
+fn render_term_frame(path: &PathBuf) -> Result<(), String> {
+    const UPPER_HALF_BLOCK: char = '\u{2580}';
+
+    let (columns, rows) = terminal::size().map_err(|e| e.to_string())?;
+    let columns = columns.max(1) as u32;
+    let rows = rows.max(1) as u32;
+
+    let oriented = decode_oriented_rgba(path)?;
+    let grid_height = rows * 2;
+    let rgba = image::imageops::resize(
+        &oriented,
+        columns,
+        grid_height,
+        image::imageops::FilterType::Triangle,
+    );
+
+    let mut stdout = std::io::stdout();
+    queue!(
+        stdout,
+        terminal::Clear(terminal::ClearType::All),
+        cursor::MoveTo(0, 0)
+    )
+    .map_err(|e| e.to_string())?;
+
+    // SetForegroundColor/SetBackgroundColor are only queued when the color actually changes from
+    // the previous cell, since most neighboring pixels share a color run and a TTY has to parse
+    // every escape sequence it's sent.
+    let mut last_fg: Option<Color> = None;
+    let mut last_bg: Option<Color> = None;
+    for row in 0..rows {
+        for col in 0..columns {
+            let top = rgba.get_pixel(col, row * 2);
+            let bottom = rgba.get_pixel(col, row * 2 + 1);
+            let fg = Color::Rgb {
+                r: top[0],
+                g: top[1],
+                b: top[2],
+            };
+            let bg = Color::Rgb {
+                r: bottom[0],
+                g: bottom[1],
+                b: bottom[2],
+            };
+            if last_fg != Some(fg) {
+                queue!(stdout, style::SetForegroundColor(fg)).map_err(|e| e.to_string())?;
+                last_fg = Some(fg);
+            }
+            if last_bg != Some(bg) {
+                queue!(stdout, style::SetBackgroundColor(bg)).map_err(|e| e.to_string())?;
+                last_bg = Some(bg);
+            }
+            queue!(stdout, style::Print(UPPER_HALF_BLOCK)).map_err(|e| e.to_string())?;
+        }
+        queue!(stdout, style::ResetColor, style::Print("\r\n")).map_err(|e| e.to_string())?;
+        last_fg = None;
+        last_bg = None;
+    }
+    stdout.flush().map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Decodes `path` into RGBA pixels with its EXIF orientation already applied.
+fn decode_oriented(path: &Path) -> Result<DecodedImage, String> {
+    let rgba = decode_oriented_rgba(path)?;
+    let (width, height) = rgba.dimensions();
+    Ok(DecodedImage {
+        width,
+        height,
+        rgba: rgba.into_raw(),
+    })
+}
+
+/// Decodes `path` and applies its EXIF orientation, shared by both the texture-upload path
+/// (`decode_oriented`) and term mode, which needs an `image::RgbaImage` it can resize further.
+fn decode_oriented_rgba(path: &Path) -> Result<image::RgbaImage, String> {
+    let orientation = read_exif_orientation(path);
+    let rgba = image::open(path).map_err(|e| e.to_string())?.to_rgba8();
+    Ok(apply_exif_orientation(rgba, orientation))
+}
+
+/// Reads the EXIF `Orientation` tag (values `1`-`8`), falling back to identity (`1`) on failure.
+fn read_exif_orientation(path: &Path) -> u32 {
+    let file = match std::fs::File::open(path) {
+        Ok(f) => f,
+        Err(_) => return 1,
+    };
+    let mut reader = std::io::BufReader::new(file);
+    let exif = match exif::Reader::new().read_from_container(&mut reader) {
+        Ok(e) => e,
+        Err(_) => return 1,
+    };
+    exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY)
+        .and_then(|field| field.value.get_uint(0))
+        .unwrap_or(1)
+}
+
+/// Applies the rotation/mirroring that EXIF orientation value `orientation` describes. Anything
+/// other than `2`-`8` is left untouched.
+fn apply_exif_orientation(img: image::RgbaImage, orientation: u32) -> image::RgbaImage {
+    match orientation {
+        // 1: identity - no tag, or the photo was already upright.
+        2 => image::imageops::flip_horizontal(&img),
+        3 => image::imageops::rotate180(&img),
+        4 => image::imageops::flip_vertical(&img),
+        5 => transpose(&img),
+        6 => image::imageops::rotate90(&img),
+        7 => transverse(&img),
+        8 => image::imageops::rotate270(&img),
+        _ => img,
+    }
+}
+
+/// Mirrors an image across its main diagonal (top-left to bottom-right), swapping width and
+/// height. This is EXIF orientation `5`.
+fn transpose(img: &image::RgbaImage) -> image::RgbaImage {
+    let (width, height) = img.dimensions();
+    image::ImageBuffer::from_fn(height, width, |x, y| *img.get_pixel(y, x))
+}
+
+/// Mirrors an image across its anti-diagonal (top-right to bottom-left), swapping width and
+/// height. This is EXIF orientation `7`.
+fn transverse(img: &image::RgbaImage) -> image::RgbaImage {
+    let (width, height) = img.dimensions();
+    image::ImageBuffer::from_fn(height, width, |x, y| {
+        *img.get_pixel(width - 1 - y, height - 1 - x)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a 2x3 (width x height) fixture whose pixels are all distinct, so a transform that
+    /// mixes up rows/columns shows up as a wrong pixel rather than a coincidental match.
+    fn fixture() -> image::RgbaImage {
+        image::ImageBuffer::from_fn(2, 3, |x, y| {
+            let v = (y * 2 + x) as u8;
+            image::Rgba([v, v, v, 255])
+        })
+    }
+
+    #[test]
+    fn apply_exif_orientation_identity_leaves_image_untouched() {
+        let img = fixture();
+        let out = apply_exif_orientation(img.clone(), 1);
+        assert_eq!(out, img);
+    }
+
+    #[test]
+    fn apply_exif_orientation_flip_horizontal() {
+        let out = apply_exif_orientation(fixture(), 2);
+        assert_eq!(out.dimensions(), (2, 3));
+        assert_eq!(out.get_pixel(0, 0), fixture().get_pixel(1, 0));
+        assert_eq!(out.get_pixel(1, 0), fixture().get_pixel(0, 0));
+    }
+
+    #[test]
+    fn apply_exif_orientation_rotate_180() {
+        let out = apply_exif_orientation(fixture(), 3);
+        assert_eq!(out.dimensions(), (2, 3));
+        assert_eq!(out.get_pixel(0, 0), fixture().get_pixel(1, 2));
+        assert_eq!(out.get_pixel(1, 2), fixture().get_pixel(0, 0));
+    }
+
+    #[test]
+    fn apply_exif_orientation_flip_vertical() {
+        let out = apply_exif_orientation(fixture(), 4);
+        assert_eq!(out.dimensions(), (2, 3));
+        assert_eq!(out.get_pixel(0, 0), fixture().get_pixel(0, 2));
+        assert_eq!(out.get_pixel(0, 2), fixture().get_pixel(0, 0));
+    }
+
+    #[test]
+    fn apply_exif_orientation_transpose_swaps_dimensions() {
+        let out = apply_exif_orientation(fixture(), 5);
+        assert_eq!(out.dimensions(), (3, 2));
+        assert_eq!(out, transpose(&fixture()));
+    }
+
+    #[test]
+    fn apply_exif_orientation_rotate_90_swaps_dimensions() {
+        let out = apply_exif_orientation(fixture(), 6);
+        assert_eq!(out.dimensions(), (3, 2));
+        assert_eq!(out, image::imageops::rotate90(&fixture()));
+    }
+
+    #[test]
+    fn apply_exif_orientation_transverse_swaps_dimensions() {
+        let out = apply_exif_orientation(fixture(), 7);
+        assert_eq!(out.dimensions(), (3, 2));
+        assert_eq!(out, transverse(&fixture()));
+    }
+
+    #[test]
+    fn apply_exif_orientation_rotate_270_swaps_dimensions() {
+        let out = apply_exif_orientation(fixture(), 8);
+        assert_eq!(out.dimensions(), (3, 2));
+        assert_eq!(out, image::imageops::rotate270(&fixture()));
+    }
+
+    #[test]
+    fn transpose_mirrors_across_main_diagonal() {
+        let img = fixture();
+        let out = transpose(&img);
+        assert_eq!(out.dimensions(), (3, 2));
+        for y in 0..3u32 {
+            for x in 0..2u32 {
+                assert_eq!(out.get_pixel(y, x), img.get_pixel(x, y));
+            }
+        }
+    }
+
+    #[test]
+    fn transverse_mirrors_across_anti_diagonal() {
+        let img = fixture();
+        let out = transverse(&img);
+        assert_eq!(out.dimensions(), (3, 2));
+        let (width, height) = img.dimensions();
+        for y in 0..3u32 {
+            for x in 0..2u32 {
+                assert_eq!(
+                    out.get_pixel(y, x),
+                    img.get_pixel(width - 1 - x, height - 1 - y)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn make_zoomed_rects_unzoomed_matches_fit_rect() {
+        let (src, dest) = make_zoomed_rects(100, 50, 200, 200, MIN_ZOOM, (0, 0)).unwrap();
+        assert_eq!(src, Rect::new(0, 0, 100, 50));
+        assert_eq!(dest, make_dst(100, 50, 200, 200));
+    }
+
+    #[test]
+    fn make_zoomed_rects_zoom_in_centers_and_crops_source() {
+        // A 100x100 image exactly fills a 100x100 viewport; zooming to 2x doubles the
+        // destination rect around the same center, and clipping it back to the viewport crops
+        // the source to its middle half.
+        let (src, dest) = make_zoomed_rects(100, 100, 100, 100, 2.0, (0, 0)).unwrap();
+        assert_eq!(dest, Rect::new(0, 0, 100, 100));
+        assert_eq!(src, Rect::new(25, 25, 50, 50));
+    }
+
+    #[test]
+    fn make_zoomed_rects_panned_fully_out_of_view_returns_none() {
+        let result = make_zoomed_rects(100, 100, 200, 200, 2.0, (10_000, 0));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn selected_or_current_falls_back_to_index_when_empty() {
+        let selected = BTreeSet::new();
+        assert_eq!(selected_or_current(&selected, 3), vec![3]);
+    }
+
+    #[test]
+    fn selected_or_current_prefers_selection_when_non_empty() {
+        let selected: BTreeSet<usize> = [1, 4, 2].into_iter().collect();
+        assert_eq!(selected_or_current(&selected, 3), vec![1, 2, 4]);
+    }
+}